@@ -1,8 +1,31 @@
 use std::{error, fmt, io, sync::Arc};
+#[cfg(feature = "backtrace")]
+use std::backtrace::{Backtrace, BacktraceStatus};
 
 /// Replacement of `std::io::Error` implementing `Eq + Clone`
-#[derive(PartialEq, Eq, Clone, Debug)]
-pub struct Error(Repr);
+#[derive(Debug)]
+pub struct Error(
+    Repr,
+    #[cfg(feature = "backtrace")] Option<Arc<Backtrace>>,
+);
+
+impl PartialEq for Error {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl Eq for Error {}
+
+impl Clone for Error {
+    fn clone(&self) -> Self {
+        Self(
+            self.0.clone(),
+            #[cfg(feature = "backtrace")]
+            self.1.clone(),
+        )
+    }
+}
 
 #[derive(PartialEq, Eq, Clone, Debug)]
 enum Repr {
@@ -51,13 +74,34 @@ impl error::Error for Error {
     }
 }
 
+/// Maximum number of links printed by [`ErrorChainDisplay`], guarding
+/// against a pathological or cyclic `source()` chain.
+const MAX_CHAIN_DEPTH: usize = 16;
+
+/// Displays an [`Error`] together with its full `source()` chain, one link
+/// per indented line (e.g. `0: <top>`, `1: <cause>`, ...). Returned by
+/// [`Error::chain_display`].
+pub struct ErrorChainDisplay<'a>(&'a Error);
+
+impl fmt::Display for ErrorChainDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut source: Option<&(dyn error::Error + 'static)> = Some(self.0);
+        for depth in 0..MAX_CHAIN_DEPTH {
+            let Some(err) = source else { break };
+            writeln!(f, "{:indent$}{depth}: {err}", "", indent = depth * 2)?;
+            source = err.source();
+        }
+        Ok(())
+    }
+}
+
 impl From<io::Error> for Error {
     fn from(e: io::Error) -> Self {
         if let Some(os) = e.raw_os_error() {
-            return Self(Repr::Os(os));
+            return Self::from_repr(Repr::Os(os));
         }
         let kind = e.kind();
-        Self(if let Some(inner) = e.into_inner() {
+        Self::from_repr(if let Some(inner) = e.into_inner() {
             Repr::Custom(kind, ArcError(inner.into()))
         } else {
             Repr::Simple(kind)
@@ -67,16 +111,45 @@ impl From<io::Error> for Error {
 
 impl From<io::ErrorKind> for Error {
     fn from(kind: io::ErrorKind) -> Self {
-        Self(Repr::Simple(kind))
+        Self::from_repr(Repr::Simple(kind))
     }
 }
 
 impl Error {
+    /// Builds an `Error` from its `Repr`, capturing a backtrace when the
+    /// `backtrace` feature is enabled. Every public constructor funnels
+    /// through here so backtrace capture stays in one place.
+    fn from_repr(repr: Repr) -> Self {
+        Self(
+            repr,
+            #[cfg(feature = "backtrace")]
+            Self::capture_backtrace(),
+        )
+    }
+
+    #[cfg(feature = "backtrace")]
+    fn capture_backtrace() -> Option<Arc<Backtrace>> {
+        let backtrace = Backtrace::capture();
+        if backtrace.status() == BacktraceStatus::Captured {
+            Some(Arc::new(backtrace))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the backtrace captured at construction time, if the
+    /// `backtrace` feature is enabled and `RUST_BACKTRACE`/
+    /// `RUST_LIB_BACKTRACE` was active when this `Error` was created.
+    #[cfg(feature = "backtrace")]
+    pub fn backtrace(&self) -> Option<&Backtrace> {
+        self.1.as_deref()
+    }
+
     pub fn new<E>(kind: io::ErrorKind, error: E) -> Self
     where
         E: Into<Arc<dyn error::Error + Send + Sync>>,
     {
-        Self(Repr::Custom(kind, ArcError(error.into())))
+        Self::from_repr(Repr::Custom(kind, ArcError(error.into())))
     }
 
     pub fn last_os_error() -> Self {
@@ -84,7 +157,7 @@ impl Error {
     }
 
     pub fn from_raw_os_error(code: i32) -> Self {
-        Self(Repr::Os(code))
+        Self::from_repr(Repr::Os(code))
     }
 
     pub fn raw_os_error(&self) -> Option<i32> {
@@ -117,6 +190,297 @@ impl Error {
             Repr::Simple(kind) | Repr::Custom(kind, _) => *kind,
         }
     }
+
+    /// Returns a `Display`-able wrapper that prints `self` and its full
+    /// `source()` chain, one link per line.
+    pub fn chain_display(&self) -> ErrorChainDisplay<'_> {
+        ErrorChainDisplay(self)
+    }
+}
+
+/// Wraps an underlying [`Error`] with a human-readable message, exposing the
+/// wrapped error as `source()` so the chain of causes stays walkable.
+#[derive(Debug)]
+struct ContextError {
+    message: String,
+    source: Error,
+}
+
+impl fmt::Display for ContextError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl error::Error for ContextError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+/// Attaches human-readable context to the `Err` path of a `Result`,
+/// preserving the original error as the `source()` of the new one and
+/// keeping its `kind()` so callers matching on `ErrorKind` still work.
+pub trait Contextualizable<T> {
+    fn context<C>(self, context: C) -> Result<T, Error>
+    where
+        C: fmt::Display;
+
+    fn with_context<C, F>(self, f: F) -> Result<T, Error>
+    where
+        C: fmt::Display,
+        F: FnOnce() -> C;
+}
+
+impl<T> Contextualizable<T> for Result<T, Error> {
+    fn context<C>(self, context: C) -> Result<T, Error>
+    where
+        C: fmt::Display,
+    {
+        self.with_context(|| context)
+    }
+
+    fn with_context<C, F>(self, f: F) -> Result<T, Error>
+    where
+        C: fmt::Display,
+        F: FnOnce() -> C,
+    {
+        self.map_err(|source| {
+            let kind = source.kind();
+            let err: Box<dyn error::Error + Send + Sync> = Box::new(ContextError {
+                message: f().to_string(),
+                source,
+            });
+            Error::new(kind, err)
+        })
+    }
+}
+
+impl<T> Contextualizable<T> for Result<T, io::Error> {
+    fn context<C>(self, context: C) -> Result<T, Error>
+    where
+        C: fmt::Display,
+    {
+        self.map_err(Error::from).context(context)
+    }
+
+    fn with_context<C, F>(self, f: F) -> Result<T, Error>
+    where
+        C: fmt::Display,
+        F: FnOnce() -> C,
+    {
+        self.map_err(Error::from).with_context(f)
+    }
+}
+
+/// A minimal `Error` implementation backed by an owned message, used to
+/// reconstruct a [`Repr::Custom`] error whose original trait object could
+/// not be preserved (e.g. after a serde round trip).
+#[cfg(feature = "serde")]
+#[derive(Debug)]
+struct StringError(String);
+
+#[cfg(feature = "serde")]
+impl fmt::Display for StringError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl error::Error for StringError {}
+
+/// Maps `io::ErrorKind` to and from a stable string name, so the mapping
+/// used for serialization stays in sync in both directions. Unknown or
+/// non-exhaustive kinds default to `"Other"` / `ErrorKind::Other`.
+#[cfg(feature = "serde")]
+macro_rules! kind_name_table {
+    ($($kind:ident => $name:literal),+ $(,)?) => {
+        fn kind_to_name(kind: io::ErrorKind) -> &'static str {
+            match kind {
+                $(io::ErrorKind::$kind => $name,)+
+                _ => "Other",
+            }
+        }
+
+        fn name_to_kind(name: &str) -> io::ErrorKind {
+            match name {
+                $($name => io::ErrorKind::$kind,)+
+                _ => io::ErrorKind::Other,
+            }
+        }
+    };
+}
+
+#[cfg(feature = "serde")]
+kind_name_table! {
+    NotFound => "NotFound",
+    PermissionDenied => "PermissionDenied",
+    ConnectionRefused => "ConnectionRefused",
+    ConnectionReset => "ConnectionReset",
+    ConnectionAborted => "ConnectionAborted",
+    NotConnected => "NotConnected",
+    AddrInUse => "AddrInUse",
+    AddrNotAvailable => "AddrNotAvailable",
+    BrokenPipe => "BrokenPipe",
+    AlreadyExists => "AlreadyExists",
+    WouldBlock => "WouldBlock",
+    InvalidInput => "InvalidInput",
+    InvalidData => "InvalidData",
+    TimedOut => "TimedOut",
+    WriteZero => "WriteZero",
+    Interrupted => "Interrupted",
+    Unsupported => "Unsupported",
+    UnexpectedEof => "UnexpectedEof",
+    OutOfMemory => "OutOfMemory",
+    Other => "Other",
+}
+
+/// On-the-wire representation of an [`Error`], gated behind the `serde`
+/// feature. `Os` keeps the raw OS code; `Simple`/`Custom` store the
+/// `ErrorKind` as its stable string name so the format does not depend on
+/// the exact ordinal of a non-exhaustive enum.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+enum SerdeRepr {
+    Os(i32),
+    Simple(String),
+    Custom(String, String),
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Error {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let repr = match &self.0 {
+            Repr::Os(os) => SerdeRepr::Os(*os),
+            Repr::Simple(kind) => SerdeRepr::Simple(kind_to_name(*kind).to_owned()),
+            Repr::Custom(kind, err) => {
+                SerdeRepr::Custom(kind_to_name(*kind).to_owned(), err.0.to_string())
+            }
+        };
+        repr.serialize(serializer)
+    }
+}
+
+/// Deserializing a `Custom` error cannot recover the original trait object,
+/// so it is reconstructed via `Error::new` backed by a [`StringError`]
+/// carrying the formatted message. This means `Arc::ptr_eq`-based equality
+/// (see [`ArcError`]) will not survive a serialize/deserialize round trip
+/// for custom errors: the result is `Eq` to itself but not to the original.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Error {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Ok(match SerdeRepr::deserialize(deserializer)? {
+            SerdeRepr::Os(os) => Self::from_raw_os_error(os),
+            SerdeRepr::Simple(name) => Self::from(name_to_kind(&name)),
+            SerdeRepr::Custom(name, message) => {
+                let err: Box<dyn error::Error + Send + Sync> = Box::new(StringError(message));
+                Self::new(name_to_kind(&name), err)
+            }
+        })
+    }
+}
+
+#[cfg(feature = "backtrace")]
+#[test]
+fn test_backtrace_excluded_from_eq_and_shared_on_clone() {
+    let e1 = Error::from(io::ErrorKind::Other);
+    let e2 = Error::from(io::ErrorKind::Other);
+    assert_eq!(e1, e2, "backtrace must not affect Eq — only Repr is compared");
+
+    let e3 = e1.clone();
+    match (&e1.1, &e3.1) {
+        (Some(a), Some(b)) => assert!(Arc::ptr_eq(a, b), "clone must share the same Arc<Backtrace>"),
+        (None, None) => {}
+        _ => panic!("clone changed whether a backtrace is present"),
+    }
+}
+
+#[test]
+fn test_chain_display_renders_indented_links() {
+    let root: Result<(), Error> = Err(Error::from(io::ErrorKind::NotFound));
+    let mid = root.context("loading").unwrap_err();
+    let top = Err::<(), Error>(mid).context("starting up").unwrap_err();
+
+    let rendered = top.chain_display().to_string();
+    let lines: Vec<&str> = rendered.lines().collect();
+    assert_eq!(lines.len(), 3);
+    assert_eq!(lines[0], "0: starting up");
+    assert_eq!(lines[1], "  1: loading");
+    assert!(lines[2].starts_with("    2: "));
+}
+
+#[test]
+fn test_chain_display_caps_pathological_chain() {
+    let mut err = Error::from(io::ErrorKind::Other);
+    for _ in 0..(MAX_CHAIN_DEPTH + 10) {
+        let result: Result<(), Error> = Err(err);
+        err = result.context("layer").unwrap_err();
+    }
+
+    let rendered = err.chain_display().to_string();
+    assert_eq!(rendered.lines().count(), MAX_CHAIN_DEPTH);
+}
+
+#[test]
+fn test_context_preserves_kind_and_chains_source() {
+    let original = Error::from(io::ErrorKind::NotFound);
+    let result: Result<(), Error> = Err(original.clone());
+
+    let wrapped = result.context("reading config").unwrap_err();
+    assert_eq!(wrapped.kind(), original.kind());
+
+    let source = error::Error::source(&wrapped).expect("context error must chain to its source");
+    assert_eq!(source.downcast_ref::<Error>(), Some(&original));
+}
+
+#[test]
+fn test_context_preserves_kind_and_chains_source_from_io_error() {
+    let io_err = io::Error::from(io::ErrorKind::PermissionDenied);
+    let kind = io_err.kind();
+    let result: Result<(), io::Error> = Err(io_err);
+
+    let wrapped = result.with_context(|| "opening file").unwrap_err();
+    assert_eq!(wrapped.kind(), kind);
+    assert!(error::Error::source(&wrapped).is_some());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_round_trip() {
+    let e = Error::from_raw_os_error(2);
+    let json = serde_json::to_string(&e).unwrap();
+    let back: Error = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.kind(), e.kind());
+
+    let e = Error::from(io::ErrorKind::WouldBlock);
+    let json = serde_json::to_string(&e).unwrap();
+    let back: Error = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.kind(), io::ErrorKind::WouldBlock);
+
+    let err: Box<dyn error::Error + Send + Sync> = Box::from("oops");
+    let e = Error::new(io::ErrorKind::InvalidInput, err);
+    let json = serde_json::to_string(&e).unwrap();
+    let back: Error = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.kind(), io::ErrorKind::InvalidInput);
+    assert_eq!(back.to_string(), "oops");
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_unknown_kind_defaults_to_other() {
+    let e = Error::from(io::ErrorKind::NotADirectory);
+    let json = serde_json::to_string(&e).unwrap();
+    assert!(json.contains("Other"), "unknown kinds serialize as \"Other\": {json}");
+
+    let back: Error = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.kind(), io::ErrorKind::Other);
 }
 
 #[test]